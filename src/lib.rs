@@ -4,6 +4,12 @@
 #![warn(missing_docs)]
 #![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(all(feature = "random", not(feature = "std")))]
+compile_error!("`random` feature requires `std` feature (Cargo.toml should declare `random = [\"std\"]`)");
+
 use xxhash_c_sys as sys;
 
 use core::{hash, mem};
@@ -50,6 +56,111 @@ pub fn xxh3_128(input: &[u8]) -> u128 {
     (result.high64 as u128) << 64 | result.low64 as u128
 }
 
+#[inline(always)]
+///Maps `hash` into the range `[0, n)` without the division cost of `hash % n`.
+///
+///The result depends on the high bits of `hash` rather than the low bits as with `hash % n`;
+///when `n` does not divide `2^32`, outputs are as uneven as with `hash % n`.
+///
+///Treats `hash` as a fraction of the full `u32` range and scales it into `[0, n)` via the
+///high word of the widened product, as used by RocksDB's hashing utilities.
+pub fn fastrange32(hash: u32, n: u32) -> u32 {
+    (((hash as u64) * (n as u64)) >> 32) as u32
+}
+
+#[inline(always)]
+///Maps `hash` into the range `[0, n)` without the division cost of `hash % n`.
+///
+///The result depends on the high bits of `hash` rather than the low bits as with `hash % n`;
+///when `n` does not divide `2^64`, outputs are as uneven as with `hash % n`.
+///
+///Treats `hash` as a fraction of the full `u64` range and scales it into `[0, n)` via the
+///high word of the widened product, as used by RocksDB's hashing utilities.
+pub fn fastrange64(hash: u64, n: u64) -> u64 {
+    (((hash as u128) * (n as u128)) >> 64) as u64
+}
+
+///Streaming version of `XXH32` algorithm.
+pub struct XXH32 {
+    state: mem::MaybeUninit<sys::XXH32_state_t>,
+}
+
+impl XXH32 {
+    #[inline]
+    ///Creates uninitialized instance.
+    ///
+    ///It is unsafe to use any method before calling `reset`
+    pub const unsafe fn uninit() -> Self {
+        let state = mem::MaybeUninit::uninit();
+        Self {
+            state
+        }
+    }
+
+    #[inline]
+    ///Creates new instance, resetting it with specified seed.
+    pub fn new(seed: u32) -> Self {
+        let mut result = unsafe {
+            Self::uninit()
+        };
+
+        result.reset(seed);
+
+        result
+    }
+
+    #[inline]
+    ///Resets hasher's state.
+    pub fn reset(&mut self, seed: u32) {
+        let result = unsafe { sys::XXH32_reset(self.state.as_mut_ptr(), seed) };
+        debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
+    }
+
+    #[inline]
+    ///Computes final 32bit hash.
+    ///
+    ///Prefer this over `Hasher::finish` on 32bit targets to avoid the zero-extension to `u64`.
+    pub fn finish32(&self) -> u32 {
+        unsafe {
+            sys::XXH32_digest(self.state.as_ptr())
+        }
+    }
+}
+
+impl hash::Hasher for XXH32 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finish32() as u64
+    }
+
+    #[inline]
+    fn write(&mut self, input: &[u8]) {
+        let result = unsafe {
+            sys::XXH32_update(self.state.as_mut_ptr(), input.as_ptr() as _, input.len())
+        };
+
+        debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
+    }
+}
+
+impl Default for XXH32 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Clone for XXH32 {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut state = mem::MaybeUninit::uninit();
+        unsafe {
+            sys::XXH32_copyState(state.as_mut_ptr(), self.state.as_ptr());
+            Self { state }
+        }
+    }
+}
+
 ///Streaming version of `XXH64` algorithm.
 pub struct XXH64 {
     state: mem::MaybeUninit<sys::XXH64_state_t>,
@@ -114,6 +225,17 @@ impl Default for XXH64 {
     }
 }
 
+impl Clone for XXH64 {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut state = mem::MaybeUninit::uninit();
+        unsafe {
+            sys::XXH64_copyState(state.as_mut_ptr(), self.state.as_ptr());
+            Self { state }
+        }
+    }
+}
+
 ///Describes method to reset XXH3 algorithm state.
 ///
 ///Policies:
@@ -121,8 +243,10 @@ impl Default for XXH64 {
 ///- Seed - updates with `u64` seed.
 ///- Secret - updates with specified slice of bytes. It should be no less than `xxhash_c_sys::XXH3_SECRET_SIZE_MIN`
 pub trait Xxh3Reset {
-    ///Reset implementation
+    ///Reset implementation for 64bit variant.
     fn reset(self, state: *mut sys::XXH3_state_t);
+    ///Reset implementation for 128bit variant.
+    fn reset128(self, state: *mut sys::XXH3_state_t);
 }
 
 ///Default reset policy.
@@ -133,6 +257,11 @@ impl Xxh3Reset for Xxh3DefaultReset {
         let result = unsafe { sys::XXH3_64bits_reset(state) };
         debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
     }
+
+    fn reset128(self, state: *mut sys::XXH3_state_t) {
+        let result = unsafe { sys::XXH3_128bits_reset(state) };
+        debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
+    }
 }
 
 impl Xxh3Reset for u64 {
@@ -140,6 +269,11 @@ impl Xxh3Reset for u64 {
         let result = unsafe { sys::XXH3_64bits_reset_withSeed(state, self) };
         debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
     }
+
+    fn reset128(self, state: *mut sys::XXH3_state_t) {
+        let result = unsafe { sys::XXH3_128bits_reset_withSeed(state, self) };
+        debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
+    }
 }
 
 impl Xxh3Reset for &'_ [u8] {
@@ -148,6 +282,35 @@ impl Xxh3Reset for &'_ [u8] {
         let result = unsafe { sys::XXH3_64bits_reset_withSecret(state, self.as_ptr() as _, self.len()) };
         debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
     }
+
+    fn reset128(self, state: *mut sys::XXH3_state_t) {
+        debug_assert!(self.len() >= xxhash_c_sys::XXH3_SECRET_SIZE_MIN);
+        let result = unsafe { sys::XXH3_128bits_reset_withSecret(state, self.as_ptr() as _, self.len()) };
+        debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
+    }
+}
+
+#[inline]
+///Generates a high-entropy secret from `custom_seed`, suitable for use with `Xxh3Reset` secret policy.
+///
+///`out` must be no less than `xxhash_c_sys::XXH3_SECRET_SIZE_MIN` in length.
+///
+///Prefer this over passing arbitrary low-entropy bytes as a secret, which weakens the hash.
+pub fn generate_secret(out: &mut [u8], custom_seed: &[u8]) {
+    assert!(out.len() >= xxhash_c_sys::XXH3_SECRET_SIZE_MIN);
+
+    let result = unsafe {
+        sys::XXH3_generateSecret(out.as_mut_ptr() as _, out.len(), custom_seed.as_ptr() as _, custom_seed.len())
+    };
+    debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
+}
+
+#[inline]
+///Generates a secret derived from `seed`, suitable for use with `Xxh3Reset` secret policy.
+pub fn generate_secret_from_seed(out: &mut [u8; sys::XXH3_SECRET_DEFAULT_SIZE as usize], seed: u64) {
+    unsafe {
+        sys::XXH3_generateSecret_fromSeed(out.as_mut_ptr() as _, seed);
+    }
 }
 
 ///Streaming version of `XXH3` 64 bit algorithm.
@@ -170,9 +333,7 @@ impl XXH3_64 {
     }
 
     #[inline]
-    ///Creates new instance.
-    ///
-    ///Returns `None` if `XXH64_reset` fails
+    ///Creates new instance, resetting it using the default policy.
     pub fn new() -> Self {
         let mut result = unsafe {
             Self::uninit()
@@ -216,3 +377,660 @@ impl Default for XXH3_64 {
         Self::new()
     }
 }
+
+impl Clone for XXH3_64 {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut state = mem::MaybeUninit::uninit();
+        unsafe {
+            sys::XXH3_copyState(state.as_mut_ptr(), self.state.as_ptr());
+            Self { state }
+        }
+    }
+}
+
+///Streaming version of `XXH3` 128 bit algorithm.
+///
+///*NOTE:* state is rather large for `XXH3` so it is advised to allocate it on heap if you plan to move it around.
+pub struct XXH3_128 {
+    state: mem::MaybeUninit<sys::XXH3_state_t>,
+}
+
+impl XXH3_128 {
+    #[inline]
+    ///Creates uninitialized instance.
+    ///
+    ///It is unsafe to use any method before calling `reset`
+    pub const unsafe fn uninit() -> Self {
+        let state = mem::MaybeUninit::uninit();
+        Self {
+            state
+        }
+    }
+
+    #[inline]
+    ///Creates new instance, resetting it using the default policy.
+    pub fn new() -> Self {
+        let mut result = unsafe {
+            Self::uninit()
+        };
+
+        result.reset(Xxh3DefaultReset);
+
+        result
+    }
+
+    #[inline(always)]
+    ///Resets hasher's state according to specified reset policy.
+    pub fn reset<R: Xxh3Reset>(&mut self, reset: R) {
+        reset.reset128(self.state.as_mut_ptr());
+    }
+
+    #[inline]
+    ///Computes final 128bit hash.
+    pub fn finish128(&self) -> u128 {
+        let result = unsafe {
+            sys::XXH3_128bits_digest(self.state.as_ptr())
+        };
+
+        (result.high64 as u128) << 64 | result.low64 as u128
+    }
+}
+
+impl hash::Hasher for XXH3_128 {
+    #[inline]
+    ///Returns low 64 bits of the final 128bit hash.
+    ///
+    ///Use `finish128` to get the full result.
+    fn finish(&self) -> u64 {
+        unsafe {
+            sys::XXH3_128bits_digest(self.state.as_ptr()).low64
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, input: &[u8]) {
+        let result = unsafe {
+            sys::XXH3_128bits_update(self.state.as_mut_ptr(), input.as_ptr() as _, input.len())
+        };
+
+        debug_assert_eq!(result, sys::XXH_errorcode_XXH_OK);
+    }
+}
+
+impl Default for XXH3_128 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for XXH3_128 {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut state = mem::MaybeUninit::uninit();
+        unsafe {
+            sys::XXH3_copyState(state.as_mut_ptr(), self.state.as_ptr());
+            Self { state }
+        }
+    }
+}
+
+///Big-endian canonical representation of `XXH32` hash.
+///
+///Useful for storing or transmitting hashes in a form that does not depend on host endianness.
+pub struct Xxh32Canonical {
+    inner: sys::XXH32_canonical_t,
+}
+
+impl Xxh32Canonical {
+    #[inline]
+    ///Converts hash into its canonical representation.
+    pub fn from_hash(value: u32) -> Self {
+        let mut inner = mem::MaybeUninit::uninit();
+        unsafe {
+            sys::XXH32_canonicalFromHash(inner.as_mut_ptr(), value);
+            Self {
+                inner: inner.assume_init(),
+            }
+        }
+    }
+
+    #[inline]
+    ///Converts canonical representation back into hash.
+    pub fn to_hash(&self) -> u32 {
+        unsafe {
+            sys::XXH32_hashFromCanonical(&self.inner)
+        }
+    }
+
+    #[inline]
+    ///Returns underlying big-endian bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner.digest
+    }
+
+    #[inline]
+    ///Creates instance from previously stored big-endian bytes.
+    pub fn from_bytes(bytes: [u8; 4]) -> Self {
+        Self {
+            inner: sys::XXH32_canonical_t { digest: bytes },
+        }
+    }
+}
+
+///Big-endian canonical representation of `XXH64` hash.
+///
+///Useful for storing or transmitting hashes in a form that does not depend on host endianness.
+pub struct Xxh64Canonical {
+    inner: sys::XXH64_canonical_t,
+}
+
+impl Xxh64Canonical {
+    #[inline]
+    ///Converts hash into its canonical representation.
+    pub fn from_hash(value: u64) -> Self {
+        let mut inner = mem::MaybeUninit::uninit();
+        unsafe {
+            sys::XXH64_canonicalFromHash(inner.as_mut_ptr(), value);
+            Self {
+                inner: inner.assume_init(),
+            }
+        }
+    }
+
+    #[inline]
+    ///Converts canonical representation back into hash.
+    pub fn to_hash(&self) -> u64 {
+        unsafe {
+            sys::XXH64_hashFromCanonical(&self.inner)
+        }
+    }
+
+    #[inline]
+    ///Returns underlying big-endian bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner.digest
+    }
+
+    #[inline]
+    ///Creates instance from previously stored big-endian bytes.
+    pub fn from_bytes(bytes: [u8; 8]) -> Self {
+        Self {
+            inner: sys::XXH64_canonical_t { digest: bytes },
+        }
+    }
+}
+
+///Big-endian canonical representation of `XXH3` 128bit hash.
+///
+///Useful for storing or transmitting hashes in a form that does not depend on host endianness.
+///This matters in particular for XXH3 128bit results, which otherwise depend on host endianness
+///when split into `high64`/`low64` words.
+pub struct Xxh128Canonical {
+    inner: sys::XXH128_canonical_t,
+}
+
+impl Xxh128Canonical {
+    #[inline]
+    ///Converts hash into its canonical representation.
+    pub fn from_hash(value: u128) -> Self {
+        let value = sys::XXH128_hash_t {
+            high64: (value >> 64) as u64,
+            low64: value as u64,
+        };
+
+        let mut inner = mem::MaybeUninit::uninit();
+        unsafe {
+            sys::XXH128_canonicalFromHash(inner.as_mut_ptr(), value);
+            Self {
+                inner: inner.assume_init(),
+            }
+        }
+    }
+
+    #[inline]
+    ///Converts canonical representation back into hash.
+    pub fn to_hash(&self) -> u128 {
+        let result = unsafe {
+            sys::XXH128_hashFromCanonical(&self.inner)
+        };
+
+        (result.high64 as u128) << 64 | result.low64 as u128
+    }
+
+    #[inline]
+    ///Returns underlying big-endian bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner.digest
+    }
+
+    #[inline]
+    ///Creates instance from previously stored big-endian bytes.
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self {
+            inner: sys::XXH128_canonical_t { digest: bytes },
+        }
+    }
+}
+
+#[cfg(feature = "random")]
+///Draws a single `u64` of entropy from the OS RNG via `std`'s `RandomState`.
+fn random_seed() -> u64 {
+    use std::collections::hash_map::RandomState;
+    use core::hash::Hasher;
+
+    hash::BuildHasher::build_hasher(&RandomState::new()).finish()
+}
+
+///`BuildHasher` for `XXH32` using a fixed seed.
+#[derive(Clone, Copy)]
+pub struct Xxh32BuildHasher {
+    seed: u32,
+}
+
+impl Xxh32BuildHasher {
+    #[inline(always)]
+    ///Creates new instance with specified seed.
+    pub const fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    #[cfg(feature = "random")]
+    #[inline]
+    ///Creates new instance with seed drawn from the OS RNG.
+    ///
+    ///Every instance gets a distinct, DoS-resistant seed, while maps built from it
+    ///stay deterministic across their lifetime.
+    ///
+    ///Requires `random` feature.
+    pub fn from_entropy() -> Self {
+        Self::new(random_seed() as u32)
+    }
+}
+
+impl Default for Xxh32BuildHasher {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl hash::BuildHasher for Xxh32BuildHasher {
+    type Hasher = XXH32;
+
+    #[inline(always)]
+    fn build_hasher(&self) -> Self::Hasher {
+        XXH32::new(self.seed)
+    }
+}
+
+///`BuildHasher` for `XXH64` using a fixed seed.
+#[derive(Clone, Copy)]
+pub struct Xxh64BuildHasher {
+    seed: u64,
+}
+
+impl Xxh64BuildHasher {
+    #[inline(always)]
+    ///Creates new instance with specified seed.
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    #[cfg(feature = "random")]
+    #[inline]
+    ///Creates new instance with seed drawn from the OS RNG.
+    ///
+    ///Every instance gets a distinct, DoS-resistant seed, while maps built from it
+    ///stay deterministic across their lifetime.
+    ///
+    ///Requires `random` feature.
+    pub fn from_entropy() -> Self {
+        Self::new(random_seed())
+    }
+}
+
+impl Default for Xxh64BuildHasher {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl hash::BuildHasher for Xxh64BuildHasher {
+    type Hasher = XXH64;
+
+    #[inline(always)]
+    fn build_hasher(&self) -> Self::Hasher {
+        XXH64::new(self.seed)
+    }
+}
+
+///Reset policy captured by [Xxh3BuildHasher](struct.Xxh3BuildHasher.html).
+#[derive(Clone, Copy)]
+enum Xxh3BuildHasherPolicy<'a> {
+    Default,
+    Seed(u64),
+    Secret(&'a [u8]),
+}
+
+impl<'a> Xxh3BuildHasherPolicy<'a> {
+    #[inline]
+    fn reset(self, hasher: &mut XXH3_64) {
+        match self {
+            Self::Default => hasher.reset(Xxh3DefaultReset),
+            Self::Seed(seed) => hasher.reset(seed),
+            Self::Secret(secret) => hasher.reset(secret),
+        }
+    }
+
+    #[inline]
+    fn reset128(self, hasher: &mut XXH3_128) {
+        match self {
+            Self::Default => hasher.reset(Xxh3DefaultReset),
+            Self::Seed(seed) => hasher.reset(seed),
+            Self::Secret(secret) => hasher.reset(secret),
+        }
+    }
+}
+
+///`BuildHasher` for `XXH3_64`, configurable with a fixed seed or secret.
+#[derive(Clone, Copy)]
+pub struct Xxh3BuildHasher<'a> {
+    policy: Xxh3BuildHasherPolicy<'a>,
+}
+
+impl Xxh3BuildHasher<'static> {
+    #[inline(always)]
+    ///Creates builder using XXH3's default reset policy.
+    pub const fn new() -> Self {
+        Self { policy: Xxh3BuildHasherPolicy::Default }
+    }
+
+    #[inline(always)]
+    ///Creates builder that resets every hasher with specified seed.
+    pub const fn with_seed(seed: u64) -> Self {
+        Self { policy: Xxh3BuildHasherPolicy::Seed(seed) }
+    }
+
+    #[cfg(feature = "random")]
+    #[inline]
+    ///Creates builder seeded from the OS RNG.
+    ///
+    ///Every instance gets a distinct, DoS-resistant seed, while maps built from it
+    ///stay deterministic across their lifetime.
+    ///
+    ///Requires `random` feature.
+    pub fn from_entropy() -> Self {
+        Self::with_seed(random_seed())
+    }
+}
+
+impl<'a> Xxh3BuildHasher<'a> {
+    #[inline(always)]
+    ///Creates builder that resets every hasher with specified secret.
+    ///
+    ///`secret` should be no less than `xxhash_c_sys::XXH3_SECRET_SIZE_MIN`.
+    pub const fn with_secret(secret: &'a [u8]) -> Self {
+        Self { policy: Xxh3BuildHasherPolicy::Secret(secret) }
+    }
+}
+
+impl Default for Xxh3BuildHasher<'static> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> hash::BuildHasher for Xxh3BuildHasher<'a> {
+    type Hasher = XXH3_64;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = XXH3_64::new();
+        self.policy.reset(&mut hasher);
+        hasher
+    }
+}
+
+#[cfg(feature = "digest")]
+///Wrapper around `XXH64` implementing `digest::Digest` interface.
+///
+///Requires `digest` feature.
+///
+///```
+///fn assert_digest<D: digest::Digest>() {}
+///
+///assert_digest::<xxhash_c::Xxh64Digest>();
+///assert_digest::<xxhash_c::Xxh3_64Digest<'static>>();
+///assert_digest::<xxhash_c::Xxh3_128Digest<'static>>();
+///```
+pub struct Xxh64Digest {
+    seed: u64,
+    inner: XXH64,
+}
+
+#[cfg(feature = "digest")]
+impl Xxh64Digest {
+    #[inline]
+    ///Creates new instance with specified seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            inner: XXH64::new(seed),
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Default for Xxh64Digest {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl digest::OutputSizeUser for Xxh64Digest {
+    type OutputSize = digest::consts::U8;
+}
+
+#[cfg(feature = "digest")]
+impl digest::Update for Xxh64Digest {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        hash::Hasher::write(&mut self.inner, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl digest::FixedOutput for Xxh64Digest {
+    #[inline]
+    fn finalize_into(self, out: &mut digest::Output<Self>) {
+        out.copy_from_slice(&hash::Hasher::finish(&self.inner).to_be_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl digest::Reset for Xxh64Digest {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset(self.seed);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl digest::HashMarker for Xxh64Digest {
+}
+
+#[cfg(feature = "digest")]
+///Wrapper around `XXH3_64` implementing `digest::Digest` interface.
+///
+///Requires `digest` feature.
+pub struct Xxh3_64Digest<'a> {
+    policy: Xxh3BuildHasherPolicy<'a>,
+    inner: XXH3_64,
+}
+
+#[cfg(feature = "digest")]
+impl Xxh3_64Digest<'static> {
+    #[inline]
+    ///Creates new instance using XXH3's default reset policy.
+    pub fn new() -> Self {
+        Self {
+            policy: Xxh3BuildHasherPolicy::Default,
+            inner: XXH3_64::new(),
+        }
+    }
+
+    #[inline]
+    ///Creates new instance that resets with specified seed.
+    pub fn with_seed(seed: u64) -> Self {
+        let policy = Xxh3BuildHasherPolicy::Seed(seed);
+        let mut inner = XXH3_64::new();
+        policy.reset(&mut inner);
+        Self { policy, inner }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> Xxh3_64Digest<'a> {
+    #[inline]
+    ///Creates new instance that resets with specified secret.
+    ///
+    ///`secret` should be no less than `xxhash_c_sys::XXH3_SECRET_SIZE_MIN`.
+    pub fn with_secret(secret: &'a [u8]) -> Self {
+        let policy = Xxh3BuildHasherPolicy::Secret(secret);
+        let mut inner = XXH3_64::new();
+        policy.reset(&mut inner);
+        Self { policy, inner }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Default for Xxh3_64Digest<'static> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::OutputSizeUser for Xxh3_64Digest<'a> {
+    type OutputSize = digest::consts::U8;
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::Update for Xxh3_64Digest<'a> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        hash::Hasher::write(&mut self.inner, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::FixedOutput for Xxh3_64Digest<'a> {
+    #[inline]
+    fn finalize_into(self, out: &mut digest::Output<Self>) {
+        out.copy_from_slice(&hash::Hasher::finish(&self.inner).to_be_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::Reset for Xxh3_64Digest<'a> {
+    #[inline]
+    fn reset(&mut self) {
+        self.policy.reset(&mut self.inner);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::HashMarker for Xxh3_64Digest<'a> {
+}
+
+#[cfg(feature = "digest")]
+///Wrapper around `XXH3_128` implementing `digest::Digest` interface.
+///
+///Requires `digest` feature.
+pub struct Xxh3_128Digest<'a> {
+    policy: Xxh3BuildHasherPolicy<'a>,
+    inner: XXH3_128,
+}
+
+#[cfg(feature = "digest")]
+impl Xxh3_128Digest<'static> {
+    #[inline]
+    ///Creates new instance using XXH3's default reset policy.
+    pub fn new() -> Self {
+        Self {
+            policy: Xxh3BuildHasherPolicy::Default,
+            inner: XXH3_128::new(),
+        }
+    }
+
+    #[inline]
+    ///Creates new instance that resets with specified seed.
+    pub fn with_seed(seed: u64) -> Self {
+        let policy = Xxh3BuildHasherPolicy::Seed(seed);
+        let mut inner = XXH3_128::new();
+        policy.reset128(&mut inner);
+        Self { policy, inner }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> Xxh3_128Digest<'a> {
+    #[inline]
+    ///Creates new instance that resets with specified secret.
+    ///
+    ///`secret` should be no less than `xxhash_c_sys::XXH3_SECRET_SIZE_MIN`.
+    pub fn with_secret(secret: &'a [u8]) -> Self {
+        let policy = Xxh3BuildHasherPolicy::Secret(secret);
+        let mut inner = XXH3_128::new();
+        policy.reset128(&mut inner);
+        Self { policy, inner }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Default for Xxh3_128Digest<'static> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::OutputSizeUser for Xxh3_128Digest<'a> {
+    type OutputSize = digest::consts::U16;
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::Update for Xxh3_128Digest<'a> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        hash::Hasher::write(&mut self.inner, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::FixedOutput for Xxh3_128Digest<'a> {
+    #[inline]
+    fn finalize_into(self, out: &mut digest::Output<Self>) {
+        out.copy_from_slice(&self.inner.finish128().to_be_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::Reset for Xxh3_128Digest<'a> {
+    #[inline]
+    fn reset(&mut self) {
+        self.policy.reset128(&mut self.inner);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'a> digest::HashMarker for Xxh3_128Digest<'a> {
+}
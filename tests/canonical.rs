@@ -0,0 +1,37 @@
+use xxhash_c::{Xxh32Canonical, Xxh64Canonical, Xxh128Canonical, xxh32, xxh64, xxh3_128};
+
+#[test]
+fn xxh32_canonical_round_trips() {
+    let data = b"loli";
+    let hash = xxh32(data, 0);
+
+    let canonical = Xxh32Canonical::from_hash(hash);
+    assert_eq!(canonical.to_hash(), hash);
+
+    let restored = Xxh32Canonical::from_bytes(canonical.as_bytes().try_into().unwrap());
+    assert_eq!(restored.to_hash(), hash);
+}
+
+#[test]
+fn xxh64_canonical_round_trips() {
+    let data = b"loli";
+    let hash = xxh64(data, 0);
+
+    let canonical = Xxh64Canonical::from_hash(hash);
+    assert_eq!(canonical.to_hash(), hash);
+
+    let restored = Xxh64Canonical::from_bytes(canonical.as_bytes().try_into().unwrap());
+    assert_eq!(restored.to_hash(), hash);
+}
+
+#[test]
+fn xxh128_canonical_round_trips() {
+    let data = b"loli";
+    let hash = xxh3_128(data);
+
+    let canonical = Xxh128Canonical::from_hash(hash);
+    assert_eq!(canonical.to_hash(), hash);
+
+    let restored = Xxh128Canonical::from_bytes(canonical.as_bytes().try_into().unwrap());
+    assert_eq!(restored.to_hash(), hash);
+}
@@ -0,0 +1,21 @@
+use xxhash_c::{fastrange32, fastrange64, xxh32, xxh64};
+
+#[test]
+fn should_stay_within_bounds() {
+    let data = b"loli";
+    let n32 = 17u32;
+    let n64 = 17u64;
+
+    for seed in 0..32 {
+        let result32 = fastrange32(xxh32(data, seed), n32);
+        assert!(result32 < n32);
+
+        let result64 = fastrange64(xxh64(data, seed as u64), n64);
+        assert!(result64 < n64);
+    }
+
+    assert_eq!(fastrange32(0, n32), 0);
+    assert_eq!(fastrange64(0, n64), 0);
+    assert_eq!(fastrange32(u32::MAX, n32), n32 - 1);
+    assert_eq!(fastrange64(u64::MAX, n64), n64 - 1);
+}
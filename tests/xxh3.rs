@@ -1,4 +1,4 @@
-use xxhash_c::{XXH3_64, xxh3_64};
+use xxhash_c::{XXH3_64, xxh3_64, XXH3_128, xxh3_128};
 use get_random_const::random;
 
 use core::mem;
@@ -53,3 +53,32 @@ fn try_reset_policies() {
     assert_ne!(result2, result4);
     assert_ne!(result1, result4);
 }
+
+#[test]
+fn clone_mid_stream_matches_original() {
+    let data = b"loli";
+
+    let mut hasher = XXH3_64::new();
+    hasher.write(&data[..2]);
+
+    let mut clone = hasher.clone();
+
+    hasher.write(&data[2..]);
+    clone.write(&data[2..]);
+
+    assert_eq!(hasher.finish(), clone.finish());
+}
+
+#[test]
+fn xxh3_128_should_work() {
+    let data = b"loli";
+
+    let result1 = xxh3_128(data);
+    assert_ne!(result1, 0);
+
+    let mut hasher = XXH3_128::new();
+    hasher.write(&data[..2]);
+    hasher.write(&data[2..]);
+    let result2 = hasher.finish128();
+    assert_eq!(result1, result2);
+}
@@ -19,3 +19,18 @@ fn should_work() {
     let result2 = hasher.finish();
     assert_eq!(result1, result2);
 }
+
+#[test]
+fn clone_mid_stream_matches_original() {
+    let data = b"loli";
+
+    let mut hasher = XXH64::new(0);
+    hasher.write(&data[..2]);
+
+    let mut clone = hasher.clone();
+
+    hasher.write(&data[2..]);
+    clone.write(&data[2..]);
+
+    assert_eq!(hasher.finish(), clone.finish());
+}